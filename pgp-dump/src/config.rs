@@ -0,0 +1,112 @@
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use serde::Deserialize;
+
+use crate::Action;
+
+/// Resolved key chord -> `Action` map, consulted by `App::handle_event` before
+/// falling back to `Action::None`.
+#[derive(Clone)]
+pub struct Keymap {
+    bindings: HashMap<KeyEvent, Action>,
+}
+
+impl Keymap {
+    /// Loads the keymap from the XDG config file (`pgp-dump/keymap.json5`),
+    /// falling back to the built-in defaults when no config file is present
+    /// or it fails to parse.
+    pub fn load() -> Self {
+        Self::load_from_disk().unwrap_or_else(Self::defaults)
+    }
+
+    pub fn lookup(&self, key: KeyEvent) -> Option<Action> {
+        self.bindings.get(&key).copied()
+    }
+
+    fn load_from_disk() -> Option<Self> {
+        let path = config_path()?;
+        let contents = std::fs::read_to_string(path).ok()?;
+        let raw: HashMap<String, Action> = json5::from_str(&contents).ok()?;
+
+        let mut bindings = HashMap::new();
+        for (chord, action) in raw {
+            match parse_chord(&chord) {
+                Some(key) => {
+                    bindings.insert(key, action);
+                }
+                None => eprintln!("pgp-dump: ignoring unrecognised key chord {chord:?}"),
+            }
+        }
+        Some(Self { bindings })
+    }
+
+    fn defaults() -> Self {
+        use Action::*;
+        use KeyCode::*;
+
+        let mut bindings = HashMap::new();
+        let mut bind = |code: KeyCode, modifiers: KeyModifiers, action: Action| {
+            bindings.insert(KeyEvent::new(code, modifiers), action);
+        };
+
+        bind(Char('q'), KeyModifiers::NONE, Quit);
+        bind(Char('c'), KeyModifiers::CONTROL, Quit);
+        bind(Left, KeyModifiers::NONE, Left);
+        bind(Right, KeyModifiers::NONE, Right);
+        bind(Up, KeyModifiers::NONE, Up);
+        bind(Down, KeyModifiers::NONE, Down);
+        bind(Char('h'), KeyModifiers::NONE, ToggleDetailMode);
+        bind(Tab, KeyModifiers::NONE, NextPane);
+
+        Self { bindings }
+    }
+}
+
+fn config_path() -> Option<PathBuf> {
+    let dirs = xdg::BaseDirectories::with_prefix("pgp-dump").ok()?;
+    dirs.find_config_file("keymap.json5")
+}
+
+/// Parses a chord such as `"q"`, `"<Ctrl-c>"` or `"<Shift-Tab>"` into a
+/// `KeyEvent`. The angle brackets are optional; modifiers are hyphen-joined
+/// and precede the final key name.
+fn parse_chord(chord: &str) -> Option<KeyEvent> {
+    let chord = chord.strip_prefix('<').unwrap_or(chord);
+    let chord = chord.strip_suffix('>').unwrap_or(chord);
+
+    let mut parts: Vec<&str> = chord.split('-').collect();
+    let key = parts.pop()?;
+
+    let mut modifiers = KeyModifiers::NONE;
+    for part in parts {
+        modifiers |= match part.to_ascii_lowercase().as_str() {
+            "ctrl" => KeyModifiers::CONTROL,
+            "alt" => KeyModifiers::ALT,
+            "shift" => KeyModifiers::SHIFT,
+            _ => return None,
+        };
+    }
+
+    let code = match key {
+        "Left" => KeyCode::Left,
+        "Right" => KeyCode::Right,
+        "Up" => KeyCode::Up,
+        "Down" => KeyCode::Down,
+        "Enter" => KeyCode::Enter,
+        "Esc" => KeyCode::Esc,
+        "Tab" => KeyCode::Tab,
+        "Backspace" => KeyCode::Backspace,
+        _ => {
+            let mut chars = key.chars();
+            let c = chars.next()?;
+            if chars.next().is_some() {
+                return None;
+            }
+            KeyCode::Char(c)
+        }
+    };
+
+    Some(KeyEvent::new(code, modifiers))
+}
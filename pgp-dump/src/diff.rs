@@ -0,0 +1,187 @@
+use ratatui::prelude::*;
+
+/// How a packet in one input lines up with the other input, once the two
+/// packet lists have been aligned.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiffStatus {
+    /// Present in both inputs and identical.
+    Same,
+    /// Present in both inputs but formats differently.
+    Changed,
+    /// Present only in the left input.
+    Removed,
+    /// Present only in the right input.
+    Added,
+}
+
+impl DiffStatus {
+    pub fn style(self) -> Style {
+        match self {
+            DiffStatus::Same => Style::default(),
+            DiffStatus::Changed => Style::new().fg(Color::Yellow),
+            DiffStatus::Removed => Style::new().fg(Color::Red),
+            DiffStatus::Added => Style::new().fg(Color::Green),
+        }
+    }
+}
+
+/// One row of the aligned packet lists: the index into the left packet list,
+/// the index into the right one (at most one may be missing), and the
+/// resulting status.
+pub struct AlignedPacket {
+    pub left: Option<usize>,
+    pub right: Option<usize>,
+    pub status: DiffStatus,
+}
+
+/// Aligns two packet lists by an LCS over `(tag, debug representation)` so
+/// that identical packets line up in order, and anything left over is
+/// reported as added/removed. Order is kept: a packet that moved is reported
+/// as removed at its old place and added at its new one.
+pub fn align_packets<'a>(
+    left: &'a [(pgp::packet::Tag, String)],
+    right: &'a [(pgp::packet::Tag, String)],
+) -> Vec<AlignedPacket> {
+    let n = left.len();
+    let m = right.len();
+
+    // Standard LCS length table.
+    let mut lcs = vec![vec![0usize; m + 1]; n + 1];
+    for i in (0..n).rev() {
+        for j in (0..m).rev() {
+            lcs[i][j] = if left[i] == right[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let mut rows = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < n && j < m {
+        if left[i] == right[j] {
+            rows.push(AlignedPacket {
+                left: Some(i),
+                right: Some(j),
+                status: DiffStatus::Same,
+            });
+            i += 1;
+            j += 1;
+        } else if lcs[i + 1][j] >= lcs[i][j + 1] {
+            rows.push(AlignedPacket {
+                left: Some(i),
+                right: None,
+                status: DiffStatus::Removed,
+            });
+            i += 1;
+        } else {
+            rows.push(AlignedPacket {
+                left: None,
+                right: Some(j),
+                status: DiffStatus::Added,
+            });
+            j += 1;
+        }
+    }
+    while i < n {
+        rows.push(AlignedPacket {
+            left: Some(i),
+            right: None,
+            status: DiffStatus::Removed,
+        });
+        i += 1;
+    }
+    while j < m {
+        rows.push(AlignedPacket {
+            left: None,
+            right: Some(j),
+            status: DiffStatus::Added,
+        });
+        j += 1;
+    }
+
+    merge_changed(rows, left, right)
+}
+
+/// A `Removed` row directly followed by an `Added` row of the same packet tag
+/// is almost always the same logical packet with different content (e.g. a
+/// re-signed certification), not an unrelated deletion plus insertion — merge
+/// each such adjacent pair into a single `Changed` row. Within a longer run
+/// of removals and additions only the pair at the boundary is merged.
+fn merge_changed(
+    rows: Vec<AlignedPacket>,
+    left: &[(pgp::packet::Tag, String)],
+    right: &[(pgp::packet::Tag, String)],
+) -> Vec<AlignedPacket> {
+    let mut out: Vec<AlignedPacket> = Vec::with_capacity(rows.len());
+    for row in rows {
+        let merged = match (out.last(), row.status) {
+            (Some(prev), DiffStatus::Added) if prev.status == DiffStatus::Removed => {
+                let (Some(i), Some(j)) = (prev.left, row.right) else {
+                    unreachable!("Removed rows always carry a left index, Added rows a right one")
+                };
+                left[i].0 == right[j].0
+            }
+            _ => false,
+        };
+
+        if merged {
+            let prev = out.last_mut().expect("checked above");
+            prev.right = row.right;
+            prev.status = DiffStatus::Changed;
+        } else {
+            out.push(row);
+        }
+    }
+    out
+}
+
+/// A line-based LCS diff of two pretty-printed packets, for the Details pane.
+/// Lines common to both sides are rendered once, as-is; a line only in `a` is
+/// prefixed `- ` in red and a line only in `b` is prefixed `+ ` in green.
+pub fn diff_lines(a: &str, b: &str) -> Vec<Line<'static>> {
+    let a_lines: Vec<&str> = a.lines().collect();
+    let b_lines: Vec<&str> = b.lines().collect();
+    let (n, m) = (a_lines.len(), b_lines.len());
+
+    let mut lcs = vec![vec![0usize; m + 1]; n + 1];
+    for i in (0..n).rev() {
+        for j in (0..m).rev() {
+            lcs[i][j] = if a_lines[i] == b_lines[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let removed = Style::new().fg(Color::Red);
+    let added = Style::new().fg(Color::Green);
+
+    let mut out = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < n && j < m {
+        if a_lines[i] == b_lines[j] {
+            out.push(Line::raw(a_lines[i].to_string()));
+            i += 1;
+            j += 1;
+        } else if lcs[i + 1][j] >= lcs[i][j + 1] {
+            out.push(Line::styled(format!("- {}", a_lines[i]), removed));
+            i += 1;
+        } else {
+            out.push(Line::styled(format!("+ {}", b_lines[j]), added));
+            j += 1;
+        }
+    }
+    while i < n {
+        out.push(Line::styled(format!("- {}", a_lines[i]), removed));
+        i += 1;
+    }
+    while j < m {
+        out.push(Line::styled(format!("+ {}", b_lines[j]), added));
+        j += 1;
+    }
+
+    out
+}
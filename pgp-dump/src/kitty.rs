@@ -0,0 +1,90 @@
+use std::io::Write;
+
+use ratatui::layout::Rect;
+
+/// Whether the current terminal is likely to understand the kitty graphics
+/// protocol, so callers can fall back to a plain description otherwise.
+pub fn supported() -> bool {
+    std::env::var("TERM")
+        .map(|term| term.contains("kitty"))
+        .unwrap_or(false)
+        || std::env::var("KITTY_WINDOW_ID").is_ok()
+}
+
+/// Writes `jpeg` (a still JPEG image, e.g. a User Attribute photo ID) to
+/// `out` using the kitty graphics protocol, positioned at the top-left of
+/// `area` and scaled to fit it.
+///
+/// Kitty's transmission formats are `f=24` (RGB), `f=32` (RGBA) and `f=100`
+/// (PNG) — there is no JPEG format code, so the image is decoded to RGBA
+/// first and sent as `f=32`. `s=`/`v=` describe the *decoded pixel data*
+/// (mandatory for raw formats), while `c=`/`r=` ask the terminal to scale
+/// that data to `area`'s cell dimensions. The payload is base64-encoded and
+/// split into the protocol's 4096-byte-per-chunk limit.
+pub fn display(jpeg: &[u8], area: Rect, out: &mut impl Write) -> std::io::Result<()> {
+    let rgba = image::load_from_memory_with_format(jpeg, image::ImageFormat::Jpeg)
+        .map_err(|err| std::io::Error::new(std::io::ErrorKind::InvalidData, err))?
+        .to_rgba8();
+    let (width, height) = rgba.dimensions();
+
+    crossterm::execute!(out, crossterm::cursor::MoveTo(area.x, area.y))?;
+
+    let payload = base64_encode(rgba.as_raw());
+    let chunks: Vec<&[u8]> = payload.as_bytes().chunks(4096).collect();
+
+    for (i, chunk) in chunks.iter().enumerate() {
+        let more = u8::from(i + 1 != chunks.len());
+        let chunk = std::str::from_utf8(chunk).expect("base64 alphabet is ASCII");
+        if i == 0 {
+            write!(
+                out,
+                "\x1b_Gf=32,a=T,s={width},v={height},c={cols},r={rows},m={more};{chunk}\x1b\\",
+                cols = area.width,
+                rows = area.height,
+            )?;
+        } else {
+            write!(out, "\x1b_Gm={more};{chunk}\x1b\\")?;
+        }
+    }
+
+    out.flush()
+}
+
+/// Deletes every image previously transmitted via `display` (and frees their
+/// data), so a stale photo doesn't linger as an overlay once the selection
+/// moves off it or onto a different one. Kitty graphics are composited
+/// independently of the terminal's cell grid, so ratatui redrawing the
+/// Details pane's text doesn't clear them on its own.
+pub fn delete_all(out: &mut impl Write) -> std::io::Result<()> {
+    write!(out, "\x1b_Ga=d,d=A;\x1b\\")?;
+    out.flush()
+}
+
+/// Minimal base64 (standard alphabet, padded) encoder, avoiding a dependency
+/// for a single escape-sequence payload.
+fn base64_encode(data: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] =
+        b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+    let mut out = String::with_capacity(data.len().div_ceil(3) * 4);
+    for chunk in data.chunks(3) {
+        let b = [
+            chunk[0],
+            *chunk.get(1).unwrap_or(&0),
+            *chunk.get(2).unwrap_or(&0),
+        ];
+        out.push(ALPHABET[(b[0] >> 2) as usize] as char);
+        out.push(ALPHABET[(((b[0] & 0x03) << 4) | (b[1] >> 4)) as usize] as char);
+        out.push(if chunk.len() > 1 {
+            ALPHABET[(((b[1] & 0x0f) << 2) | (b[2] >> 6)) as usize] as char
+        } else {
+            '='
+        });
+        out.push(if chunk.len() > 2 {
+            ALPHABET[(b[2] & 0x3f) as usize] as char
+        } else {
+            '='
+        });
+    }
+    out
+}
@@ -1,9 +1,30 @@
 use color_eyre::eyre::Result;
-use crossterm::event::KeyCode;
+use futures::StreamExt;
+use notify::Watcher;
+use pgp::ser::Serialize;
 use ratatui::{prelude::*, widgets::*};
+use serde::Deserialize;
 use tokio::sync::mpsc;
 use tui_tree_widget::{Tree, TreeItem, TreeState};
 
+mod config;
+mod diff;
+mod kitty;
+use config::Keymap;
+use diff::DiffStatus;
+
+/// Extracts the raw JPEG bytes out of a User Attribute packet's image
+/// variant, if it has one, so the Details pane can render a photo ID.
+fn jpeg_bytes(packet: &pgp::packet::Packet) -> Option<&[u8]> {
+    let pgp::packet::Packet::UserAttribute(attr) = packet else {
+        return None;
+    };
+    match attr {
+        pgp::packet::UserAttribute::Image { data, .. } => Some(data.as_ref()),
+        pgp::packet::UserAttribute::Unknown { .. } => None,
+    }
+}
+
 pub fn initialize_panic_handler() {
     let original_hook = std::panic::take_hook();
     std::panic::set_hook(Box::new(move |panic_info| {
@@ -30,16 +51,287 @@ struct App<'a> {
     state: TreeState<usize>,
     items: Vec<TreeItem<'a, usize>>,
     packets: Vec<pgp::packet::Packet>,
+    raw: Vec<Option<Vec<u8>>>,
+    detail_mode: DetailMode,
+    keymap: Keymap,
+    path: std::path::PathBuf,
+    /// Area and packet index of a photo ID that `draw` wants rendered via the
+    /// kitty graphics protocol, filled in by `draw` and consumed by
+    /// `flush_photo` once ratatui's own frame has been flushed.
+    pending_photo: Option<(Rect, usize)>,
+    /// Packet index of the photo ID last written to the terminal, so
+    /// `flush_photo` doesn't re-emit the same image (and its flicker) on
+    /// every redraw when the selection hasn't changed.
+    last_rendered_photo: Option<usize>,
 }
 
-impl App<'_> {
-    fn new(action_tx: mpsc::UnboundedSender<Action>, packets: Vec<pgp::packet::Packet>) -> Self {
-        let mut items = Vec::new();
+/// A `Read` wrapper that mirrors every byte pulled through it into a shared
+/// buffer, so the exact dearmored bytes `PacketParser` consumed for each
+/// packet can be sliced back out afterwards. This is deliberately not a
+/// re-serialization: malformed or non-canonical framing needs to show up in
+/// the hex pane exactly as it appeared in the input, not normalized.
+struct RecordingReader<R> {
+    inner: R,
+    consumed: std::rc::Rc<std::cell::RefCell<Vec<u8>>>,
+}
+
+impl<R: std::io::Read> std::io::Read for RecordingReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let n = self.inner.read(buf)?;
+        self.consumed.borrow_mut().extend_from_slice(&buf[..n]);
+        Ok(n)
+    }
+}
+
+/// Runs the Dearmor + PacketParser pipeline against `path`, returning the
+/// parsed packets alongside the raw input bytes each one consumed. A raw
+/// entry is `None` only if the consumed byte range couldn't be sliced out
+/// cleanly; that degrades the hex pane for just that packet rather than
+/// failing the whole load.
+async fn read_packets(
+    path: &std::path::Path,
+) -> Result<(Vec<pgp::packet::Packet>, Vec<Option<Vec<u8>>>)> {
+    let file = tokio::fs::read_to_string(path).await?;
+
+    let mut dearmor = pgp::armor::Dearmor::new(file.as_bytes());
+    dearmor.read_header()?;
+
+    let consumed = std::rc::Rc::new(std::cell::RefCell::new(Vec::new()));
+    let reader = RecordingReader {
+        inner: dearmor,
+        consumed: std::rc::Rc::clone(&consumed),
+    };
+
+    let mut packets = Vec::new();
+    let mut raw = Vec::new();
+    let mut offset = 0;
+    for result in pgp::packet::PacketParser::new(reader) {
+        packets.push(result?);
+        let end = consumed.borrow().len();
+        raw.push(consumed.borrow().get(offset..end).map(<[u8]>::to_vec));
+        offset = end;
+    }
+
+    Ok((packets, raw))
+}
+
+/// Which representation the Details pane renders for the selected packet.
+#[derive(Clone, Copy, PartialEq)]
+enum DetailMode {
+    Debug,
+    Hex,
+}
+
+/// Number of leading bytes that make up the OpenPGP packet header (the tag
+/// octet plus the length octets), derived from the framing rules in RFC 4880
+/// §4.2 so the hex dump can highlight them separately from the packet body.
+fn packet_header_len(raw: &[u8]) -> usize {
+    let Some(&first) = raw.first() else {
+        return 0;
+    };
+
+    if first & 0x40 != 0 {
+        // New format: 1 tag octet followed by 1, 2 or 5 length octets.
+        match raw.get(1) {
+            Some(0..=191) => 2,
+            Some(192..=223) => 3,
+            Some(255) => 6,
+            Some(_) => 2, // partial body length, encoded in a single octet
+            None => 1,
+        }
+    } else {
+        // Old format: length type is encoded in the two low bits of the tag octet.
+        let header_len = match first & 0x03 {
+            0 => 2,
+            1 => 3,
+            2 => 5,
+            _ => 1, // indeterminate length, no length octets at all
+        };
+        header_len.min(raw.len())
+    }
+}
+
+/// Renders `data` as a classic 16-bytes-per-row hex dump: an offset gutter,
+/// the hex columns, and an ASCII gutter, with the packet header bytes styled
+/// distinctly from the body.
+fn hex_dump_lines(data: &[u8], header_len: usize) -> Vec<Line<'static>> {
+    let header_style = Style::new().fg(Color::Yellow).add_modifier(Modifier::BOLD);
+    let offset_style = Style::new().fg(Color::DarkGray);
+
+    data.chunks(16)
+        .enumerate()
+        .map(|(row, chunk)| {
+            let offset = row * 16;
+            let mut spans = vec![Span::styled(format!("{offset:08x}  "), offset_style)];
+
+            for col in 0..16 {
+                match chunk.get(col) {
+                    Some(byte) => {
+                        let style = if offset + col < header_len {
+                            header_style
+                        } else {
+                            Style::default()
+                        };
+                        spans.push(Span::styled(format!("{byte:02x} "), style));
+                    }
+                    None => spans.push(Span::raw("   ")),
+                }
+                if col == 7 {
+                    spans.push(Span::raw(" "));
+                }
+            }
+
+            spans.push(Span::raw(" "));
+            for (col, byte) in chunk.iter().enumerate() {
+                let ch = if byte.is_ascii_graphic() || *byte == b' ' {
+                    *byte as char
+                } else {
+                    '.'
+                };
+                let style = if offset + col < header_len {
+                    header_style
+                } else {
+                    Style::default()
+                };
+                spans.push(Span::styled(ch.to_string(), style));
+            }
+
+            Line::from(spans)
+        })
+        .collect()
+}
+
+/// A group of packets that are still being assembled into a single `TreeItem`.
+///
+/// `id` is the index (into the flat packet list) that the finished node will be
+/// identified by, so the Details pane can keep resolving selections via
+/// `self.packets[*i]` without a separate synthetic id space.
+struct OpenGroup<'a> {
+    id: usize,
+    label: Text<'a>,
+    children: Vec<TreeItem<'a, usize>>,
+}
+
+impl<'a> OpenGroup<'a> {
+    fn new(id: usize, label: Text<'a>) -> Self {
+        Self {
+            id,
+            label,
+            children: Vec::new(),
+        }
+    }
+
+    fn finish(self) -> TreeItem<'a, usize> {
+        if self.children.is_empty() {
+            TreeItem::new_leaf(self.id, self.label)
+        } else {
+            TreeItem::new(self.id, self.label, self.children).expect("unique child identifiers")
+        }
+    }
+}
+
+/// Walks the flat packet list and groups packets into the OpenPGP objects they
+/// actually belong to: a primary key owns its signatures, User IDs/Attributes
+/// and subkeys (each subkey owning its own binding signatures), and a message
+/// groups its PKESK/SKESK/encrypted-data/literal/signature packets together.
+///
+/// `styles`, when given, overrides the label style of individual packets by
+/// index — used by diff mode to color rows added/removed/changed.
+fn build_tree(
+    packets: &[pgp::packet::Packet],
+    styles: Option<&std::collections::HashMap<usize, Style>>,
+) -> Vec<TreeItem<'static, usize>> {
+    use pgp::packet::Tag;
+
+    let label = |i: usize, name: String| -> Text<'static> {
+        match styles.and_then(|s| s.get(&i)) {
+            Some(style) => Text::from(Line::styled(name, *style)),
+            None => Text::from(name),
+        }
+    };
+
+    let mut items = Vec::new();
+    let mut key: Option<OpenGroup> = None;
+    let mut subkey: Option<OpenGroup> = None;
+    let mut message: Option<OpenGroup> = None;
+
+    let flush_subkey = |key: &mut Option<OpenGroup>, subkey: &mut Option<OpenGroup>| {
+        if let Some(sk) = subkey.take() {
+            key.as_mut()
+                .expect("a subkey can only be open while its key is open")
+                .children
+                .push(sk.finish());
+        }
+    };
 
-        for (i, packet) in packets.iter().enumerate() {
-            let name = format!("{:?}", packet.tag());
-            items.push(TreeItem::new_leaf(i, name));
+    for (i, packet) in packets.iter().enumerate() {
+        let name = format!("{:?}", packet.tag());
+        match packet.tag() {
+            Tag::SecretKey | Tag::PublicKey => {
+                flush_subkey(&mut key, &mut subkey);
+                if let Some(k) = key.take() {
+                    items.push(k.finish());
+                }
+                if let Some(m) = message.take() {
+                    items.push(m.finish());
+                }
+                key = Some(OpenGroup::new(i, label(i, name)));
+            }
+            Tag::SecretSubkey | Tag::PublicSubkey => {
+                flush_subkey(&mut key, &mut subkey);
+                if key.is_some() {
+                    subkey = Some(OpenGroup::new(i, label(i, name)));
+                } else {
+                    // A subkey without a preceding primary key: treat it like its own group.
+                    items.push(TreeItem::new_leaf(i, label(i, name)));
+                }
+            }
+            Tag::Signature | Tag::UserId | Tag::UserAttribute => {
+                if let Some(sk) = subkey.as_mut() {
+                    sk.children.push(TreeItem::new_leaf(i, label(i, name)));
+                } else if let Some(k) = key.as_mut() {
+                    k.children.push(TreeItem::new_leaf(i, label(i, name)));
+                } else {
+                    message
+                        .get_or_insert_with(|| {
+                            OpenGroup::new(i, Text::from("Message".to_string()))
+                        })
+                        .children
+                        .push(TreeItem::new_leaf(i, label(i, name)));
+                }
+            }
+            _ => {
+                flush_subkey(&mut key, &mut subkey);
+                if let Some(k) = key.take() {
+                    items.push(k.finish());
+                }
+                message
+                    .get_or_insert_with(|| OpenGroup::new(i, Text::from("Message".to_string())))
+                    .children
+                    .push(TreeItem::new_leaf(i, label(i, name)));
+            }
         }
+    }
+
+    flush_subkey(&mut key, &mut subkey);
+    if let Some(k) = key.take() {
+        items.push(k.finish());
+    }
+    if let Some(m) = message.take() {
+        items.push(m.finish());
+    }
+
+    items
+}
+
+impl App<'_> {
+    fn new(
+        action_tx: mpsc::UnboundedSender<Action>,
+        packets: Vec<pgp::packet::Packet>,
+        raw: Vec<Option<Vec<u8>>>,
+        path: std::path::PathBuf,
+    ) -> Self {
+        let items = build_tree(&packets, None);
 
         Self {
             should_quit: false,
@@ -47,6 +339,31 @@ impl App<'_> {
             state: TreeState::default(),
             items,
             packets,
+            raw,
+            detail_mode: DetailMode::Debug,
+            keymap: Keymap::load(),
+            path,
+            pending_photo: None,
+            last_rendered_photo: None,
+        }
+    }
+
+    /// Re-runs the parsing pipeline against `self.path` and rebuilds the tree
+    /// in place, keeping the current selection if it still points at a valid
+    /// packet index.
+    async fn reload(&mut self) {
+        match read_packets(&self.path).await {
+            Ok((packets, raw)) => {
+                self.items = build_tree(&packets, None);
+                self.packets = packets;
+                self.raw = raw;
+
+                let selected = self.state.selected();
+                if selected.iter().any(|id| *id >= self.packets.len()) {
+                    self.state.select(Vec::new());
+                }
+            }
+            Err(err) => eprintln!("pgp-dump: failed to reload {:?}: {err}", self.path),
         }
     }
 
@@ -85,25 +402,108 @@ impl App<'_> {
             .highlight_symbol(">> ");
         f.render_stateful_widget(widget, layout[0], &mut self.state);
 
-        let text = if let Some(i) = self.state.selected().last() {
-            format!("{:#?}", self.packets[*i])
+        self.pending_photo = None;
+
+        let photo = self
+            .state
+            .selected()
+            .last()
+            .filter(|i| jpeg_bytes(&self.packets[**i]).is_some());
+
+        if let Some(&i) = photo {
+            self.draw_photo(f, layout[1], i);
+            return;
+        }
+
+        let details = if let Some(i) = self.state.selected().last() {
+            match self.detail_mode {
+                DetailMode::Debug => Text::from(format!("{:#?}", self.packets[*i])),
+                DetailMode::Hex => match &self.raw[*i] {
+                    Some(raw) => Text::from(hex_dump_lines(raw, packet_header_len(raw))),
+                    None => Text::from("(raw bytes unavailable for this packet)"),
+                },
+            }
         } else {
-            "Nothing selected".to_string()
+            Text::from("Nothing selected")
         };
 
         f.render_widget(
-            Paragraph::new(text).block(
+            Paragraph::new(details).block(
                 Block::new()
                     // don't render the right border because it will be rendered by the right block
                     .border_set(symbols::border::PLAIN)
                     .borders(Borders::TOP | Borders::LEFT | Borders::BOTTOM | Borders::RIGHT)
-                    .title("Details"),
+                    .title(match self.detail_mode {
+                        DetailMode::Debug => "Details",
+                        DetailMode::Hex => "Details (hex)",
+                    }),
             ),
             layout[1],
         );
     }
 
-    fn update(&mut self, msg: Action) -> Action {
+    /// Renders the Details pane's border for a selected User Attribute photo
+    /// ID, either queuing the photo for the kitty graphics protocol or
+    /// falling back to a plain metadata line.
+    ///
+    /// The kitty escape itself isn't written here: this runs inside
+    /// `terminal.draw`'s closure, and ratatui flushes its own frame buffer
+    /// right after that closure returns, which would paint over (and
+    /// flicker against) raw bytes written to the same terminal in the
+    /// meantime. Instead this stashes `(area, packet index)` in
+    /// `pending_photo` for `flush_photo` to emit once the frame is down.
+    fn draw_photo(&mut self, f: &mut Frame, area: Rect, packet_index: usize) {
+        let jpeg = jpeg_bytes(&self.packets[packet_index]).expect("caller checked jpeg_bytes");
+
+        let block = Block::new()
+            .border_set(symbols::border::PLAIN)
+            .borders(Borders::TOP | Borders::LEFT | Borders::BOTTOM | Borders::RIGHT)
+            .title("Details (photo)");
+        let inner = block.inner(area);
+        f.render_widget(block, area);
+
+        if kitty::supported() {
+            self.pending_photo = Some((inner, packet_index));
+        } else {
+            f.render_widget(
+                Paragraph::new(format!(
+                    "User Attribute: JPEG image, {} bytes\n\
+                     (this terminal doesn't support the kitty graphics protocol)",
+                    jpeg.len()
+                )),
+                inner,
+            );
+        }
+    }
+
+    /// Emits the kitty graphics escape for `pending_photo`, if any, now that
+    /// ratatui has flushed its own frame and won't clobber the raw bytes.
+    /// Re-selecting the same photo across redraws is a no-op, since resending
+    /// the full image payload every frame would flicker for no reason. When
+    /// the selection leaves a photo or moves to a different one, the
+    /// previous image is deleted first — kitty graphics are an overlay that
+    /// otherwise keeps painting over whatever text ratatui draws next.
+    fn flush_photo(&mut self, out: &mut impl std::io::Write) -> Result<()> {
+        match self.pending_photo {
+            Some((area, i)) if self.last_rendered_photo != Some(i) => {
+                if self.last_rendered_photo.is_some() {
+                    kitty::delete_all(out)?;
+                }
+                let jpeg = jpeg_bytes(&self.packets[i]).expect("draw_photo only queues photos");
+                kitty::display(jpeg, area, out)?;
+                self.last_rendered_photo = Some(i);
+            }
+            Some(_) => {}
+            None => {
+                if self.last_rendered_photo.take().is_some() {
+                    kitty::delete_all(out)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    async fn update(&mut self, msg: Action) -> Action {
         match msg {
             Action::Quit => self.should_quit = true, // You can handle cleanup and exit here
             Action::Up => {
@@ -118,37 +518,77 @@ impl App<'_> {
             Action::Right => {
                 self.state.key_right();
             }
-            Action::None => {}
+            Action::ToggleDetailMode => {
+                self.detail_mode = match self.detail_mode {
+                    DetailMode::Debug => DetailMode::Hex,
+                    DetailMode::Hex => DetailMode::Debug,
+                };
+            }
+            Action::Reload => self.reload().await,
+            Action::NextPane | Action::None => {}
         };
         Action::None
     }
 
+    /// Drives crossterm's async event stream and a filesystem watcher on
+    /// `self.path`'s parent directory side by side, forwarding key presses
+    /// (through the keymap) and file-change notifications (as
+    /// `Action::Reload`) to `action_tx`.
+    ///
+    /// The watch targets the *directory*, not the file itself: gpg and most
+    /// editors replace a file by writing a temp file and renaming it into
+    /// place, which moves the inode out from under a watch held on the file
+    /// path directly and is reported as Create/Remove, not Modify. Watching
+    /// the directory and matching events by file name survives that.
     fn handle_event(&self) -> tokio::task::JoinHandle<()> {
-        let tick_rate = std::time::Duration::from_millis(250);
         let tx = self.action_tx.clone();
+        let keymap = self.keymap.clone();
+        let path = self.path.clone();
         tokio::spawn(async move {
+            let mut events = crossterm::event::EventStream::new();
+            let (watch_tx, mut watch_rx) = mpsc::unbounded_channel();
+
+            let watch_dir = path
+                .parent()
+                .filter(|dir| !dir.as_os_str().is_empty())
+                .map_or_else(|| std::path::PathBuf::from("."), std::path::Path::to_path_buf);
+            let file_name = path.file_name().map(std::ffi::OsStr::to_owned);
+
+            let mut watcher = match notify::recommended_watcher(
+                move |res: notify::Result<notify::Event>| {
+                    let is_our_file = matches!(&res, Ok(event)
+                        if event.paths.iter().any(|p| p.file_name() == file_name.as_deref()));
+                    if is_our_file {
+                        let _ = watch_tx.send(());
+                    }
+                },
+            ) {
+                Ok(watcher) => Some(watcher),
+                Err(err) => {
+                    eprintln!("pgp-dump: failed to create filesystem watcher: {err}");
+                    None
+                }
+            };
+            if let Some(watcher) = watcher.as_mut() {
+                if let Err(err) = watcher.watch(&watch_dir, notify::RecursiveMode::NonRecursive) {
+                    eprintln!("pgp-dump: failed to watch {watch_dir:?}: {err}");
+                }
+            }
+
             loop {
-                let action = if crossterm::event::poll(tick_rate).unwrap() {
-                    if let crossterm::event::Event::Key(key) = crossterm::event::read().unwrap() {
-                        if key.kind == crossterm::event::KeyEventKind::Press {
-                            match key.code {
-                                KeyCode::Char('q') => Action::Quit,
-                                KeyCode::Left => Action::Left,
-                                KeyCode::Right => Action::Right,
-                                KeyCode::Down => Action::Down,
-                                KeyCode::Up => Action::Up,
-                                _ => Action::None,
-                            }
-                        } else {
-                            Action::None
+                let action = tokio::select! {
+                    Some(event) = events.next() => match event {
+                        Ok(crossterm::event::Event::Key(key))
+                            if key.kind == crossterm::event::KeyEventKind::Press =>
+                        {
+                            keymap.lookup(key).unwrap_or(Action::None)
                         }
-                    } else {
-                        Action::None
-                    }
-                } else {
-                    Action::None
+                        _ => Action::None,
+                    },
+                    Some(()) = watch_rx.recv() => Action::Reload,
+                    else => break,
                 };
-                if let Err(_) = tx.send(action) {
+                if tx.send(action).is_err() {
                     break;
                 }
             }
@@ -156,24 +596,270 @@ impl App<'_> {
     }
 }
 
-#[derive(PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
 enum Action {
     Left,
     Right,
     Down,
     Up,
+    ToggleDetailMode,
+    Reload,
+    NextPane,
     Quit,
     None,
 }
 
-async fn run(packets: Vec<pgp::packet::Packet>) -> Result<()> {
+/// Drives the draw/update loop against any `ratatui::backend::Backend`, so
+/// the same core can run against a real terminal or, in tests, a
+/// `TestBackend`.
+async fn run_app<B: ratatui::backend::Backend>(
+    terminal: &mut Terminal<B>,
+    app: &mut App<'_>,
+    action_rx: &mut mpsc::UnboundedReceiver<Action>,
+) -> Result<()> {
+    loop {
+        terminal.draw(|f| {
+            app.draw(f);
+        })?;
+
+        app.flush_photo(&mut std::io::stderr())?;
+
+        if let Some(action) = action_rx.recv().await {
+            app.update(action).await;
+        }
+
+        if app.should_quit {
+            break;
+        }
+    }
+
+    Ok(())
+}
+
+async fn run(
+    packets: Vec<pgp::packet::Packet>,
+    raw: Vec<Option<Vec<u8>>>,
+    path: std::path::PathBuf,
+) -> Result<()> {
     let mut t = Terminal::new(CrosstermBackend::new(std::io::stderr()))?;
 
     let (action_tx, mut action_rx) = mpsc::unbounded_channel();
 
-    let mut app = App::new(action_tx, packets);
+    let mut app = App::new(action_tx, packets, raw, path);
     let task = app.handle_event();
 
+    run_app(&mut t, &mut app, &mut action_rx).await?;
+
+    task.abort();
+
+    Ok(())
+}
+
+/// Which side of a diff-mode comparison currently has keyboard focus.
+#[derive(Clone, Copy, PartialEq)]
+enum Pane {
+    Left,
+    Right,
+}
+
+/// Side-by-side comparison of two PGP inputs: one packet tree per side, each
+/// row colored by its `DiffStatus`, plus a Details pane showing a line-level
+/// diff of the currently selected pair.
+struct DiffApp {
+    should_quit: bool,
+    focus: Pane,
+    left_items: Vec<TreeItem<'static, usize>>,
+    right_items: Vec<TreeItem<'static, usize>>,
+    left_state: TreeState<usize>,
+    right_state: TreeState<usize>,
+    left_packets: Vec<pgp::packet::Packet>,
+    right_packets: Vec<pgp::packet::Packet>,
+    alignment: Vec<diff::AlignedPacket>,
+    keymap: Keymap,
+}
+
+impl DiffApp {
+    fn new(
+        left_packets: Vec<pgp::packet::Packet>,
+        right_packets: Vec<pgp::packet::Packet>,
+    ) -> Self {
+        let left_fingerprint: Vec<_> = left_packets
+            .iter()
+            .map(|p| (p.tag(), format!("{p:#?}")))
+            .collect();
+        let right_fingerprint: Vec<_> = right_packets
+            .iter()
+            .map(|p| (p.tag(), format!("{p:#?}")))
+            .collect();
+        let alignment = diff::align_packets(&left_fingerprint, &right_fingerprint);
+
+        let mut left_styles = std::collections::HashMap::new();
+        let mut right_styles = std::collections::HashMap::new();
+        for row in &alignment {
+            if row.status == DiffStatus::Same {
+                continue;
+            }
+            if let Some(i) = row.left {
+                left_styles.insert(i, row.status.style());
+            }
+            if let Some(j) = row.right {
+                right_styles.insert(j, row.status.style());
+            }
+        }
+
+        Self {
+            should_quit: false,
+            focus: Pane::Left,
+            left_items: build_tree(&left_packets, Some(&left_styles)),
+            right_items: build_tree(&right_packets, Some(&right_styles)),
+            left_state: TreeState::default(),
+            right_state: TreeState::default(),
+            left_packets,
+            right_packets,
+            alignment,
+            keymap: Keymap::load(),
+        }
+    }
+
+    /// Looks up the alignment row for whichever packet is selected in the
+    /// focused pane, so the Details pane can show both sides of the pair.
+    fn selected_pair(&self) -> Option<(Option<usize>, Option<usize>)> {
+        let (state, side_of) = match self.focus {
+            Pane::Left => (&self.left_state, |row: &diff::AlignedPacket| row.left),
+            Pane::Right => (&self.right_state, |row: &diff::AlignedPacket| row.right),
+        };
+        let selected = *state.selected().last()?;
+        self.alignment
+            .iter()
+            .find(|row| side_of(row) == Some(selected))
+            .map(|row| (row.left, row.right))
+    }
+
+    fn draw(&mut self, f: &mut Frame) {
+        let area = f.size();
+        let layout = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([
+                Constraint::Percentage(33),
+                Constraint::Percentage(33),
+                Constraint::Percentage(34),
+            ])
+            .split(area);
+
+        let tree_block = |title: &'static str, focused: bool| {
+            Block::new()
+                .title(title)
+                .border_set(symbols::border::PLAIN)
+                .borders(Borders::ALL)
+                .border_style(if focused {
+                    Style::new().fg(Color::LightGreen)
+                } else {
+                    Style::default()
+                })
+        };
+
+        let left = Tree::new(self.left_items.clone())
+            .expect("all item identifiers are unique")
+            .block(tree_block("Left", self.focus == Pane::Left))
+            .highlight_style(
+                Style::new()
+                    .fg(Color::Black)
+                    .bg(Color::LightGreen)
+                    .add_modifier(Modifier::BOLD),
+            )
+            .highlight_symbol(">> ");
+        f.render_stateful_widget(left, layout[0], &mut self.left_state);
+
+        let right = Tree::new(self.right_items.clone())
+            .expect("all item identifiers are unique")
+            .block(tree_block("Right", self.focus == Pane::Right))
+            .highlight_style(
+                Style::new()
+                    .fg(Color::Black)
+                    .bg(Color::LightGreen)
+                    .add_modifier(Modifier::BOLD),
+            )
+            .highlight_symbol(">> ");
+        f.render_stateful_widget(right, layout[1], &mut self.right_state);
+
+        let details = match self.selected_pair() {
+            Some((left, right)) => {
+                let left_text = left.map(|i| format!("{:#?}", self.left_packets[i]));
+                let right_text = right.map(|j| format!("{:#?}", self.right_packets[j]));
+                diff::diff_lines(
+                    left_text.as_deref().unwrap_or(""),
+                    right_text.as_deref().unwrap_or(""),
+                )
+            }
+            None => vec![Line::raw("Nothing selected")],
+        };
+
+        f.render_widget(
+            Paragraph::new(details).block(
+                Block::new()
+                    .border_set(symbols::border::PLAIN)
+                    .borders(Borders::ALL)
+                    .title("Details (diff)"),
+            ),
+            layout[2],
+        );
+    }
+
+    fn update(&mut self, msg: Action) {
+        let (items, state) = match self.focus {
+            Pane::Left => (&self.left_items, &mut self.left_state),
+            Pane::Right => (&self.right_items, &mut self.right_state),
+        };
+        match msg {
+            Action::Quit => self.should_quit = true,
+            Action::Up => state.key_up(items),
+            Action::Down => state.key_down(items),
+            Action::Left => state.key_left(),
+            Action::Right => state.key_right(),
+            Action::NextPane => {
+                self.focus = match self.focus {
+                    Pane::Left => Pane::Right,
+                    Pane::Right => Pane::Left,
+                };
+            }
+            Action::ToggleDetailMode | Action::Reload | Action::None => {}
+        }
+    }
+
+    fn handle_event(
+        &self,
+        action_tx: mpsc::UnboundedSender<Action>,
+    ) -> tokio::task::JoinHandle<()> {
+        let keymap = self.keymap.clone();
+        tokio::spawn(async move {
+            let mut events = crossterm::event::EventStream::new();
+            while let Some(Ok(event)) = events.next().await {
+                let action = match event {
+                    crossterm::event::Event::Key(key)
+                        if key.kind == crossterm::event::KeyEventKind::Press =>
+                    {
+                        keymap.lookup(key).unwrap_or(Action::None)
+                    }
+                    _ => Action::None,
+                };
+                if action_tx.send(action).is_err() {
+                    break;
+                }
+            }
+        })
+    }
+}
+
+async fn run_diff(left: std::path::PathBuf, right: std::path::PathBuf) -> Result<()> {
+    let mut t = Terminal::new(CrosstermBackend::new(std::io::stderr()))?;
+
+    let (left_packets, _) = read_packets(&left).await?;
+    let (right_packets, _) = read_packets(&right).await?;
+
+    let (action_tx, mut action_rx) = mpsc::unbounded_channel();
+    let mut app = DiffApp::new(left_packets, right_packets);
+    let task = app.handle_event(action_tx);
+
     loop {
         t.draw(|f| {
             app.draw(f);
@@ -197,15 +883,140 @@ async fn run(packets: Vec<pgp::packet::Packet>) -> Result<()> {
 async fn main() -> Result<()> {
     initialize_panic_handler();
 
-    let file = std::env::args().nth(1).expect("missing file");
-    let file = tokio::fs::read_to_string(file).await?;
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    match args.as_slice() {
+        [left, right] => {
+            let left = std::path::PathBuf::from(left);
+            let right = std::path::PathBuf::from(right);
+            startup()?;
+            let result = run_diff(left, right).await;
+            shutdown()?;
+            result
+        }
+        [path] => {
+            let path = std::path::PathBuf::from(path);
+            let (packets, raw) = read_packets(&path).await?;
+            startup()?;
+            let result = run(packets, raw, path).await;
+            shutdown()?;
+            result
+        }
+        _ => panic!("usage: pgp-dump <file> [other-file]"),
+    }
+}
 
-    let mut dearmor = pgp::armor::Dearmor::new(file.as_bytes());
-    dearmor.read_header()?;
-    let packets = pgp::packet::PacketParser::new(dearmor).collect::<Result<_, _>>()?;
+#[cfg(test)]
+mod tests {
+    use ratatui::backend::TestBackend;
+    use ratatui::buffer::Buffer;
 
-    startup()?;
-    run(packets).await?;
-    shutdown()?;
-    Ok(())
+    use super::*;
+
+    const PUBKEY: &str = include_str!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/tests/fixtures/pubkey.asc"
+    ));
+    const MESSAGE: &str = include_str!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/tests/fixtures/message.asc"
+    ));
+    const SIGNATURE: &str = include_str!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/tests/fixtures/signature.asc"
+    ));
+
+    fn parse(armored: &str) -> (Vec<pgp::packet::Packet>, Vec<Option<Vec<u8>>>) {
+        let mut dearmor = pgp::armor::Dearmor::new(armored.as_bytes());
+        dearmor.read_header().unwrap();
+        let packets: Vec<pgp::packet::Packet> = pgp::packet::PacketParser::new(dearmor)
+            .collect::<Result<_, _>>()
+            .unwrap();
+        let raw = packets.iter().map(|p| Some(p.to_bytes().unwrap())).collect();
+        (packets, raw)
+    }
+
+    fn buffer_text(buffer: &Buffer) -> String {
+        let mut out = String::new();
+        for y in 0..buffer.area.height {
+            for x in 0..buffer.area.width {
+                out.push_str(buffer.get(x, y).symbol());
+            }
+            out.push('\n');
+        }
+        out
+    }
+
+    /// Drives `App` against a `TestBackend`, feeding `actions` in order and
+    /// returning the buffer as it stood right before the final `Quit`.
+    async fn render(armored: &str, actions: &[Action]) -> Buffer {
+        let (packets, raw) = parse(armored);
+        let (action_tx, mut action_rx) = mpsc::unbounded_channel();
+        let mut app = App::new(
+            action_tx.clone(),
+            packets,
+            raw,
+            std::path::PathBuf::from("fixture.asc"),
+        );
+        let mut terminal = Terminal::new(TestBackend::new(80, 24)).unwrap();
+
+        for action in actions {
+            action_tx.send(*action).unwrap();
+        }
+        action_tx.send(Action::Quit).unwrap();
+
+        run_app(&mut terminal, &mut app, &mut action_rx)
+            .await
+            .unwrap();
+
+        terminal.backend().buffer().clone()
+    }
+
+    #[tokio::test]
+    async fn renders_the_packet_tree_with_nothing_selected() {
+        let buffer = render(PUBKEY, &[]).await;
+        let text = buffer_text(&buffer);
+
+        assert!(text.contains("Packets"));
+        assert!(text.contains("Details"));
+        assert!(text.contains("PublicKey"));
+        assert!(text.contains("Nothing selected"));
+    }
+
+    #[tokio::test]
+    async fn selecting_a_packet_highlights_it_and_fills_the_details_pane() {
+        let buffer = render(PUBKEY, &[Action::Down]).await;
+        let text = buffer_text(&buffer);
+
+        assert!(text.contains(">> "));
+        assert!(!text.contains("Nothing selected"));
+    }
+
+    #[tokio::test]
+    async fn toggling_detail_mode_switches_to_the_hex_dump() {
+        let buffer = render(PUBKEY, &[Action::Down, Action::ToggleDetailMode]).await;
+        let text = buffer_text(&buffer);
+
+        assert!(text.contains("Details (hex)"));
+        assert!(text.contains("00000000"));
+    }
+
+    #[tokio::test]
+    async fn renders_a_detached_signature_fixture() {
+        // A lone detached signature has no key or message to attach to, so it
+        // is grouped under a synthetic "Message" node; expand it to see the
+        // packet itself.
+        let buffer = render(SIGNATURE, &[Action::Down, Action::Right]).await;
+        let text = buffer_text(&buffer);
+
+        assert!(text.contains("Signature"));
+    }
+
+    #[tokio::test]
+    async fn renders_an_encrypted_message_fixture() {
+        let buffer = render(MESSAGE, &[]).await;
+        let text = buffer_text(&buffer);
+
+        assert!(text.contains("Packets"));
+        assert!(text.contains("Details"));
+    }
 }